@@ -1,6 +1,10 @@
-// use alloc::vec::{self, Vec};
 use std::cell::{Cell, RefCell, Ref};
-use std::vec;
+use std::collections::VecDeque;
+
+/// Upper bound on how many drained group buffers `DefrayInner` keeps
+/// around for reuse. Pathological inputs (many short-lived groups) must
+/// not grow this pool without bound.
+const POOL_CAPACITY: usize = 16;
 
 #[derive(Clone)]
 struct DefrayInner<I>
@@ -22,9 +26,12 @@ where
     /// that range is large enough.
     bottom_group: usize,
     /// Buffered groups, from `bottom_group` (index 0) to `top_group`.
-    buffer: Vec<vec::IntoIter<I::Item>>,
+    buffer: Vec<VecDeque<I::Item>>,
     /// index of last group iter that was dropped, usize::MAX == none
     dropped_group: usize,
+    /// Drained group buffers kept around to avoid reallocating one per
+    /// group when many groups are materialized out of order.
+    pool: Vec<VecDeque<I::Item>>,
 }
 
 impl<I> DefrayInner<I>
@@ -62,8 +69,14 @@ where
         if client < self.oldest_buffered_group {
             return None;
         }
-        let elt = self.buffer.get_mut(bufidx).and_then(|queue| queue.next());
+        let elt = self.buffer.get_mut(bufidx).and_then(|queue| queue.pop_front());
         if elt.is_none() && client == self.oldest_buffered_group {
+            // Reclaim the now-empty buffer's allocation for reuse before
+            // it's dropped by the `retain` below.
+            let drained = self.buffer.get_mut(bufidx).map(std::mem::take);
+            if let Some(buf) = drained {
+                self.recycle(buf);
+            }
             // FIXME: VecDeque is unfortunately not zero allocation when empty,
             // so we do this job manually.
             // `bottom_group..oldest_buffered_group` is unused, and if it's large enough, erase it.
@@ -72,7 +85,7 @@ where
             while self
                 .buffer
                 .get(self.oldest_buffered_group - self.bottom_group)
-                .map_or(false, |buf| buf.len() == 0)
+                .is_some_and(|buf| buf.is_empty())
             {
                 self.oldest_buffered_group += 1;
             }
@@ -82,7 +95,7 @@ where
                 let mut i = 0;
                 self.buffer.retain(|buf| {
                     i += 1;
-                    debug_assert!(buf.len() == 0 || i > nclear);
+                    debug_assert!(buf.is_empty() || i > nclear);
                     i > nclear
                 });
                 self.bottom_group = self.oldest_buffered_group;
@@ -119,11 +132,11 @@ where
         // Because the `Groups` iterator is always the first to request
         // each group index, client is the next index efter top_group.
         debug_assert!(self.top_group + 1 == client);
-        let mut group = Vec::new();
+        let mut group = self.take_pooled();
 
         if let Some(elt) = self.current_elt.take() {
             if self.top_group != self.dropped_group {
-                group.push(elt);
+                group.push_back(elt);
             }
         }
 
@@ -131,7 +144,7 @@ where
             match self.next_element() {
                 Some(elt) => {
                     if self.top_group != self.dropped_group {
-                        group.push(elt);
+                        group.push_back(elt);
                     }
                 }
                 None => {
@@ -144,6 +157,8 @@ where
 
         if self.top_group != self.dropped_group {
             self.push_next_group(group);
+        } else {
+            self.recycle(group);
         }
         if first_elt.is_some() {
             self.top_group += 1;
@@ -152,20 +167,35 @@ where
         first_elt
     }
 
-    fn push_next_group(&mut self, group: Vec<I::Item>) {
+    fn push_next_group(&mut self, group: VecDeque<I::Item>) {
         // When we add a new buffered group, fill up slots between oldest_buffered_group and top_group
         while self.top_group - self.bottom_group > self.buffer.len() {
             if self.buffer.is_empty() {
                 self.bottom_group += 1;
                 self.oldest_buffered_group += 1;
             } else {
-                self.buffer.push(Vec::new().into_iter());
+                self.buffer.push(VecDeque::new());
             }
         }
-        self.buffer.push(group.into_iter());
+        self.buffer.push(group);
         debug_assert!(self.top_group + 1 - self.bottom_group == self.buffer.len());
     }
 
+    /// Pop a drained buffer off the freelist, ready to be filled with a
+    /// new group's elements, or allocate one if the pool is empty.
+    fn take_pooled(&mut self) -> VecDeque<I::Item> {
+        self.pool.pop().unwrap_or_default()
+    }
+
+    /// Return a drained buffer's allocation to the freelist, bounded so
+    /// pathological inputs don't grow it without limit.
+    fn recycle(&mut self, mut buf: VecDeque<I::Item>) {
+        if self.pool.len() < POOL_CAPACITY {
+            buf.clear();
+            self.pool.push(buf);
+        }
+    }
+
     /// This is the immediate case, where we use no buffering
     #[inline]
     fn step_current(&mut self) -> Option<I::Item> {
@@ -225,7 +255,8 @@ pub struct Map<'a, I: Iterator,F> {
 
 impl<'a, B, I: Iterator, F> Map<'a, I,F>
     where
-    F: FnMut(Group<I>) -> B {
+    I: Keyed,
+    F: FnMut(Group<'a, I>) -> B {
     pub fn new(into: &'a Defray<I>, f: F) -> Self {
         Self {
             into,
@@ -236,6 +267,7 @@ impl<'a, B, I: Iterator, F> Map<'a, I,F>
 
 impl<'a, B, I: Iterator, F> IntoIterator for &'a Map<'a, I,F>
     where
+    I: Keyed + ChunkSize,
     F: FnMut(Group<'a, I>) -> B + Clone {
 
     type Item = B;
@@ -269,6 +301,7 @@ where
                 bottom_group: 0,
                 buffer: Vec::new(),
                 dropped_group: !0,
+                pool: Vec::new(),
             }),
             index: Cell::new(0),
         }
@@ -285,7 +318,10 @@ where
     }
 
     pub fn map<'a, F, B>(&'a self, f: F) -> Map<'a, I, F>
-        where F: Fn(Group<I>) -> B {
+    where
+        I: Keyed,
+        F: Fn(Group<'a, I>) -> B,
+    {
         Map::new(self, f)
     }
 
@@ -299,9 +335,36 @@ where
 
 }
 
+impl<I> Defray<I>
+where
+    I: Iterator + Keyed + ChunkSize,
+{
+    /// Eagerly drain all groups, in order, into owned vectors.
+    ///
+    /// Unlike iterating `&Defray` directly, this never hands out a
+    /// `Group<'_, I>` borrowing from `self`, so callers don't have to
+    /// juggle lifetimes to keep more than one group's elements around.
+    /// Uses the source's [`ChunkSize`] hint, when available, to
+    /// pre-reserve each group's vector.
+    pub fn into_grouped_vecs(self) -> Vec<Vec<I::Item>> {
+        let chunk_size = self.iter_ref().chunk_size();
+        (&self)
+            .into_iter()
+            .map(|group| {
+                let mut v = match chunk_size {
+                    Some(n) => Vec::with_capacity(n),
+                    None => Vec::new(),
+                };
+                v.extend(group);
+                v
+            })
+            .collect()
+    }
+}
+
 impl<'a, I> IntoIterator for &'a Defray<I>
 where
-    I: Iterator,
+    I: Iterator + Keyed + ChunkSize,
     I::Item: 'a,
 {
     type Item = Group<'a, I>;
@@ -312,6 +375,45 @@ where
     }
 }
 
+/// Lets a `Frayed` source report the key of the group that just produced
+/// an element, so `Group` can expose it alongside the group's elements --
+/// mirroring itertools' `(K, Group)` element type for `group_by`.
+///
+/// There's deliberately no blanket impl over `Frayed` here: a blanket
+/// `impl<T: Frayed> Keyed for T` would conflict (E0119) with any `Frayed`
+/// type that also needs its own `Keyed` impl, such as
+/// [`fraught::fray_by::FrayBy`](crate::fraught::fray_by::FrayBy). Instead,
+/// every plain adapter implements `Keyed` itself with `Key = ()`.
+pub trait Keyed {
+    type Key;
+
+    /// Returns the key of the group currently being produced.
+    ///
+    /// # Panics
+    ///
+    /// Implementations that derive the key from elements already seen
+    /// (such as [`fraught::fray_by::FrayBy`](crate::fraught::fray_by::FrayBy))
+    /// may panic if called before this iterator has yielded its first
+    /// element. `Group::key()`, the advertised way to read a key, always
+    /// satisfies this precondition -- it's only a hazard when calling
+    /// `key()` directly on the source iterator.
+    fn key(&self) -> Self::Key;
+}
+
+/// Lets a `Frayed` source report an upper bound on how many elements any
+/// one of its groups can contain, so `Group` and `Groups` can give
+/// tighter `size_hint`s than the pessimistic default.
+///
+/// Defaults to `None`, so a plain adapter only needs an empty impl (see
+/// [`Keyed`] for why this isn't a blanket impl over `Frayed`);
+/// [`fraught::chunks::Chunks`](crate::fraught::chunks::Chunks) overrides
+/// it with its fixed chunk size.
+pub trait ChunkSize {
+    fn chunk_size(&self) -> Option<usize> {
+        None
+    }
+}
+
 /// An iterator that yields the Group iterators.
 ///
 /// Iterator element type is `(K, Group)`:
@@ -329,7 +431,7 @@ where
 
 impl<'a, I> Iterator for Groups<'a, I>
 where
-    I: Iterator,
+    I: Iterator + Keyed + ChunkSize,
     I::Item: 'a,
 {
     type Item = Group<'a, I>;
@@ -339,12 +441,38 @@ where
         let index = self.parent.index.get();
         self.parent.index.set(index + 1);
         let inner = &mut *self.parent.inner.borrow_mut();
-        inner.step(index).map(|elt| Group {
-            parent: self.parent,
-            index,
-            first: Some(elt),
+        inner.step(index).map(|elt| {
+            // The element we just fetched is always the first of its
+            // group, so the source's key for it is the group's key.
+            let key = inner.iter.key();
+            Group {
+                parent: self.parent,
+                index,
+                first: Some(elt),
+                key,
+            }
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let inner = self.parent.inner.borrow();
+        let (lo, hi) = inner.iter.size_hint();
+        match inner.iter.chunk_size() {
+            Some(n) if n > 0 => {
+                let groups = |elems: usize| elems.div_ceil(n);
+                // If the source is partway through a group that was
+                // already yielded, up to `n - 1` of its remaining elements
+                // belong to that group rather than to a new one.
+                let lower = if inner.top_group < self.parent.index.get() {
+                    groups(lo).saturating_sub(1)
+                } else {
+                    groups(lo)
+                };
+                (lower, hi.map(groups))
+            }
+            _ => (0, None),
+        }
+    }
 }
 
 /// An iterator for the elements in a single group.
@@ -352,17 +480,32 @@ where
 /// Iterator element type is `I::Item`.
 pub struct Group<'a, I: 'a>
 where
-    I: Iterator,
+    I: Iterator + Keyed,
     I::Item: 'a,
 {
     pub parent: &'a Defray<I>,
     index: usize,
     first: Option<I::Item>,
+    key: I::Key,
+}
+
+impl<'a, I> Group<'a, I>
+where
+    I: Iterator + Keyed,
+    I::Item: 'a,
+{
+    /// The key of this group, as reported by the `Frayed` source.
+    ///
+    /// Plain adapters have no notion of key and report `&()`; groups
+    /// produced by `fray_by` report the key that delimited them.
+    pub fn key(&self) -> &I::Key {
+        &self.key
+    }
 }
 
 impl<'a, I> Drop for Group<'a, I>
 where
-    I: Iterator,
+    I: Iterator + Keyed,
     I::Item: 'a,
 {
     fn drop(&mut self) {
@@ -372,7 +515,7 @@ where
 
 impl<'a, I> Iterator for Group<'a, I>
 where
-    I: Iterator,
+    I: Iterator + Keyed + ChunkSize,
     I::Item: 'a,
 {
     type Item = I::Item;
@@ -383,12 +526,24 @@ where
         }
         self.parent.step(self.index)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = if self.first.is_some() { 1 } else { 0 };
+        let inner = self.parent.inner.borrow();
+        let upper = match inner.iter.chunk_size() {
+            // A group from a fixed-size `chunks(n)` source has at most
+            // `n` elements total.
+            Some(n) => Some(n),
+            None => inner.iter.size_hint().1.map(|h| h + lower),
+        };
+        (lower, upper)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{FraughtTools, Frayed, FrayedTools};
-    use super::*;
+    use crate::{FraughtTools, FrayedTools};
+    use crate::test_support::SevenIter;
 
     #[test]
     fn test_vec_into_iter_clone() {
@@ -399,18 +554,6 @@ mod tests {
         assert_eq!(i.count(), 3);
     }
 
-    struct SevenIter(u8);
-    /// SevenIter(0) returns 1, 2, None, 3, 4, None, 7, None, None, ...
-    impl Iterator for SevenIter {
-        type Item = u8;
-        fn next(&mut self) -> Option<u8> {
-            self.0 += 1;
-            (self.0 % 3 != 0 && self.0 <= 7).then_some(self.0)
-        }
-    }
-    /// Mark iterator as `Frayed`
-    impl Frayed for SevenIter {}
-
     #[test]
     fn test_prefix() {
         let v = vec![1, 2, 3];
@@ -508,4 +651,20 @@ mod tests {
         let v: Vec<u8> = split.into_iter().collect();
         assert_eq!(v, vec![3, 9, 7]);
     }
+
+    #[test]
+    fn into_grouped_vecs() {
+        let defray = (0..7).chunks(3).defray();
+        let v = defray.into_grouped_vecs();
+        assert_eq!(v, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+    }
+
+    #[test]
+    fn group_size_hint_uses_chunk_size() {
+        let defray = (0..7).chunks(3).defray();
+        let mut groups = (&defray).into_iter();
+        assert_eq!(groups.size_hint(), (3, Some(3)));
+        let first = groups.next().unwrap();
+        assert_eq!(first.size_hint(), (1, Some(3)));
+    }
 }
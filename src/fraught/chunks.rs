@@ -0,0 +1,88 @@
+use crate::defray::{ChunkSize, Keyed};
+use crate::Frayed;
+use std::iter::Peekable;
+
+/// Groups the wrapped iterator into fixed-size runs by fraying it every
+/// `size` elements, mirroring itertools' `IntoChunks` but expressed as a
+/// `Frayed` adapter.
+///
+/// Built with a `Peekable` source so a boundary `None` is never emitted
+/// right before the real end of the stream -- two consecutive `None`s mean
+/// "done" in this crate, so a trailing, empty chunk must not be signaled.
+///
+/// Reports its own `chunk_size()` (see [`ChunkSize`]) so `.defray()` can
+/// give accurate `size_hint`s.
+pub struct Chunks<I: Iterator> {
+    size: usize,
+    index: usize,
+    iter: Peekable<I>,
+}
+
+impl<I: Iterator> Chunks<I> {
+    pub fn new(size: usize, iter: I) -> Self {
+        assert!(size > 0, "chunk size must be greater than zero");
+        Chunks {
+            size,
+            index: 0,
+            iter: iter.peekable(),
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Chunks<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.size {
+            self.index = 0;
+            if self.iter.peek().is_some() {
+                return None;
+            }
+        }
+        let elt = self.iter.next();
+        if elt.is_some() {
+            self.index += 1;
+        }
+        elt
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every `Some` we yield is a real element pulled straight from
+        // `iter`; the boundary `None`s don't consume one, so the source's
+        // own bounds still hold for us.
+        self.iter.size_hint()
+    }
+}
+
+impl<I: Iterator> Frayed for Chunks<I> {}
+
+impl<I: Iterator> Keyed for Chunks<I> {
+    type Key = ();
+
+    fn key(&self) -> Self::Key {}
+}
+
+impl<I: Iterator> ChunkSize for Chunks<I> {
+    fn chunk_size(&self) -> Option<usize> {
+        Some(self.size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FraughtTools, FrayedTools};
+
+    #[test]
+    fn test_chunks() {
+        let split = (0..10).chunks(3).defray();
+        let groups: Vec<Vec<i32>> = (&split).into_iter().map(|g| g.collect()).collect();
+        assert_eq!(groups, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8], vec![9]]);
+    }
+
+    #[test]
+    fn test_chunks_exact() {
+        let split = (0..6).chunks(3).defray();
+        let groups: Vec<Vec<i32>> = (&split).into_iter().map(|g| g.collect()).collect();
+        assert_eq!(groups, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+    }
+}
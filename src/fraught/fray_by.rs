@@ -0,0 +1,161 @@
+use crate::Frayed;
+use std::iter::Peekable;
+
+/// Frays the wrapped iterator wherever the key returned by `F` changes
+/// between consecutive elements, mirroring itertools' `group_by` but
+/// expressed as a fraying operation.
+///
+/// Built with a `Peekable` source: elements are yielded while
+/// `key(current) == key(peek())`, and a single `None` boundary is emitted
+/// right when the key is about to change.
+///
+/// Reports its own [`crate::defray::Keyed::key`], threaded through to
+/// [`crate::defray::Group::key`].
+pub struct FrayBy<I: Iterator, K, F> {
+    iter: Peekable<I>,
+    key: F,
+    current_key: Option<K>,
+    boundary_pending: bool,
+}
+
+impl<I, K, F> FrayBy<I, K, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    pub fn new(iter: I, key: F) -> Self {
+        FrayBy {
+            iter: iter.peekable(),
+            key,
+            current_key: None,
+            boundary_pending: false,
+        }
+    }
+}
+
+impl<I, K, F> Iterator for FrayBy<I, K, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.boundary_pending {
+            self.boundary_pending = false;
+            return None;
+        }
+        let elt = self.iter.next()?;
+        let k = (self.key)(&elt);
+        self.boundary_pending = match self.iter.peek() {
+            Some(next_elt) => (self.key)(next_elt) != k,
+            None => false,
+        };
+        self.current_key = Some(k);
+        Some(elt)
+    }
+}
+
+impl<I, K, F> Frayed for FrayBy<I, K, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+}
+
+impl<I, K, F> crate::defray::Keyed for FrayBy<I, K, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq + Clone,
+{
+    type Key = K;
+
+    /// # Panics
+    ///
+    /// Panics if called before this `FrayBy` has yielded its first
+    /// element -- there is no key to report yet. Reading the key through
+    /// [`crate::defray::Group::key`] never hits this, since a `Group` is
+    /// only produced after its first element has been pulled.
+    fn key(&self) -> K {
+        self.current_key
+            .clone()
+            .expect("key() called before any element was yielded")
+    }
+}
+
+impl<I, K, F> crate::defray::ChunkSize for FrayBy<I, K, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Defray;
+
+    #[test]
+    fn test_fray_by() {
+        let v = vec![1, 1, 2, 2, 2, 3];
+        let frayed = FrayBy::new(v.into_iter(), |x: &i32| *x);
+        let defray: Defray<_> = Defray::new(frayed);
+        let groups: Vec<(i32, Vec<i32>)> = (&defray)
+            .into_iter()
+            .map(|g| (*g.key(), g.collect()))
+            .collect();
+        assert_eq!(groups, vec![(1, vec![1, 1]), (2, vec![2, 2, 2]), (3, vec![3])]);
+    }
+
+    /// `Group::key()` is captured eagerly at `Groups::next()` time, so it
+    /// must stay correct even when groups are collected out of order --
+    /// mirrors `split_unfused_out_of_order` in `src/defray.rs`.
+    #[test]
+    fn test_fray_by_out_of_order() {
+        let v = vec![1, 1, 2, 2, 2, 3];
+        let frayed = FrayBy::new(v.into_iter(), |x: &i32| *x);
+        let defray: Defray<_> = Defray::new(frayed);
+        let mut iters = (&defray).into_iter();
+        let first = iters.next().unwrap();
+        let second = iters.next().unwrap();
+        let third = iters.next().unwrap();
+        assert!(iters.next().is_none());
+        assert!(iters.next().is_none());
+
+        assert_eq!(*third.key(), 3);
+        let v: Vec<_> = third.collect();
+        assert_eq!(v, [3]);
+        assert_eq!(*second.key(), 2);
+        let v: Vec<_> = second.collect();
+        assert_eq!(v, [2, 2, 2]);
+        assert_eq!(*first.key(), 1);
+        let v: Vec<_> = first.collect();
+        assert_eq!(v, [1, 1]);
+    }
+
+    /// Same invariant as `test_fray_by_out_of_order`, but with a group
+    /// dropped unread -- mirrors `split_unfused_drop_second`.
+    #[test]
+    fn test_fray_by_drop_second() {
+        let v = vec![1, 1, 2, 2, 2, 3];
+        let frayed = FrayBy::new(v.into_iter(), |x: &i32| *x);
+        let defray: Defray<_> = Defray::new(frayed);
+        let mut iters = (&defray).into_iter();
+        let first = iters.next().unwrap();
+        assert_eq!(*first.key(), 1);
+        let v: Vec<_> = first.collect();
+        assert_eq!(v, [1, 1]);
+
+        // Drop this one.
+        let _ = iters.next();
+        let third = iters.next().unwrap();
+        assert_eq!(*third.key(), 3);
+        let v: Vec<_> = third.collect();
+        assert_eq!(v, [3]);
+        assert!(iters.next().is_none());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod prefix;
+pub mod chunks;
+pub mod fray_by;
+pub mod suffix;
@@ -1,3 +1,4 @@
+use crate::defray::{ChunkSize, Keyed};
 use crate::Frayed;
 use std::iter::Peekable;
 
@@ -69,3 +70,20 @@ where
     J: Frayed<Item = I::Item>,
 {
 }
+
+impl<I, J> Keyed for Prefix<I, J>
+where
+    I: Iterator + Clone,
+    J: Frayed<Item = I::Item>,
+{
+    type Key = ();
+
+    fn key(&self) -> Self::Key {}
+}
+
+impl<I, J> ChunkSize for Prefix<I, J>
+where
+    I: Iterator + Clone,
+    J: Frayed<Item = I::Item>,
+{
+}
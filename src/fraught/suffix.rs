@@ -0,0 +1,163 @@
+use crate::defray::{ChunkSize, Keyed};
+use crate::Frayed;
+
+/// Appends a clone of `tail` after each frayed group, symmetric to
+/// [`Prefix`](crate::fraught::prefix::Prefix) prepending a shared prefix
+/// before each group.
+pub struct Suffix<I, J: Iterator> {
+    tail: I,
+    iter: J,
+    consume: Option<I>,
+    /// Whether the group currently in progress has yielded a real element.
+    group_nonempty: bool,
+    suffix_empty: bool,
+    /// Set once the source has signaled true end-of-stream (an empty
+    /// group right after another), so we never replay the suffix twice.
+    exhausted: bool,
+}
+
+impl<I, J> Suffix<I, J>
+where
+    I: Iterator + Clone,
+    J: Frayed<Item = I::Item>,
+{
+    pub fn new(tail: I, iter: J) -> Self {
+        Suffix {
+            tail,
+            iter,
+            consume: None,
+            group_nonempty: false,
+            suffix_empty: false,
+            exhausted: false,
+        }
+    }
+
+    /// If enabled, a trailing empty group (one whose boundary immediately
+    /// follows the previous one, with nothing in between) still receives
+    /// the suffix. By default it is disabled.
+    pub fn suffix_empty(mut self, enable: bool) -> Self {
+        self.suffix_empty = enable;
+        self
+    }
+
+    fn start_suffix(&mut self) -> Option<I::Item> {
+        let mut tail = self.tail.clone();
+        match tail.next() {
+            Some(elt) => {
+                self.consume = Some(tail);
+                Some(elt)
+            }
+            None => None,
+        }
+    }
+
+    fn step(&mut self) -> Option<I::Item> {
+        if self.exhausted {
+            return None;
+        }
+        match self.iter.next() {
+            Some(elt) => {
+                self.group_nonempty = true;
+                Some(elt)
+            }
+            None => {
+                if self.group_nonempty {
+                    self.group_nonempty = false;
+                    self.start_suffix()
+                } else {
+                    // Two boundaries with nothing in between: the source
+                    // is genuinely done. Decide once whether that final,
+                    // empty group still gets a suffix, then never again.
+                    self.exhausted = true;
+                    if self.suffix_empty {
+                        self.start_suffix()
+                    } else {
+                        None
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<I, J> Iterator for Suffix<I, J>
+where
+    I: Iterator + Clone,
+    J: Frayed<Item = I::Item>,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.consume {
+            Some(ref mut tail) => match tail.next() {
+                Some(elt) => Some(elt),
+                None => {
+                    self.consume = None;
+                    // the suffix just drained; now signal the boundary.
+                    None
+                }
+            },
+            None => self.step(),
+        }
+    }
+}
+
+impl<I, J> Frayed for Suffix<I, J>
+where
+    I: Iterator + Clone,
+    J: Frayed<Item = I::Item>,
+{
+}
+
+impl<I, J> Keyed for Suffix<I, J>
+where
+    I: Iterator + Clone,
+    J: Frayed<Item = I::Item>,
+{
+    type Key = ();
+
+    fn key(&self) -> Self::Key {}
+}
+
+impl<I, J> ChunkSize for Suffix<I, J>
+where
+    I: Iterator + Clone,
+    J: Frayed<Item = I::Item>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FraughtTools, FrayedTools};
+    use crate::test_support::SevenIter;
+
+    #[test]
+    fn test_suffix() {
+        let split = SevenIter(0).suffix(vec![0].into_iter()).defray();
+        let groups: Vec<Vec<u8>> = (&split).into_iter().map(|g| g.collect()).collect();
+        assert_eq!(groups, vec![vec![1, 2, 0], vec![4, 5, 0], vec![7, 0]]);
+    }
+
+    #[test]
+    fn test_suffix_empty() {
+        let split = SevenIter(0)
+            .suffix(vec![0].into_iter())
+            .suffix_empty(true)
+            .defray();
+        let groups: Vec<Vec<u8>> = (&split).into_iter().map(|g| g.collect()).collect();
+        assert_eq!(
+            groups,
+            vec![vec![1, 2, 0], vec![4, 5, 0], vec![7, 0], vec![0]]
+        );
+    }
+
+    #[test]
+    fn test_suffix_on_chunks() {
+        // `chunks()` must stay `Frayed` for this composition to typecheck.
+        let split = (0..10).chunks(3).suffix(vec![0].into_iter()).defray();
+        let groups: Vec<Vec<i32>> = (&split).into_iter().map(|g| g.collect()).collect();
+        assert_eq!(
+            groups,
+            vec![vec![0, 1, 2, 0], vec![3, 4, 5, 0], vec![6, 7, 8, 0], vec![9, 0]]
+        );
+    }
+}
@@ -2,8 +2,14 @@
 #![doc = include_str!("../README.md")]
 pub mod fraught;
 pub mod defray;
+#[cfg(test)]
+pub(crate) mod test_support;
 pub use defray::Defray;
+use defray::{ChunkSize, Keyed};
+use fraught::chunks::Chunks;
+use fraught::fray_by::FrayBy;
 use fraught::prefix::Prefix;
+use fraught::suffix::Suffix;
 
 /// Marker trait
 pub trait Frayed: Iterator {}
@@ -47,6 +53,48 @@ pub trait FraughtTools: Iterator {
     {
         FrayedIter { unfused: self }
     }
+
+    /// Fray this iterator into fixed-size groups of at most `size`
+    /// elements, so `.defray()` yields a group per chunk.
+    ///
+    /// ```
+    /// use frayed::{FraughtTools, FrayedTools};
+    /// let split = (0..10).chunks(3).defray();
+    /// let groups: Vec<Vec<i32>> = (&split).into_iter().map(|g| g.collect()).collect();
+    /// assert_eq!(groups, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8], vec![9]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is `0`.
+    fn chunks(self, size: usize) -> Chunks<Self>
+    where
+        Self: Sized,
+    {
+        Chunks::new(size, self)
+    }
+
+    /// Fray this iterator wherever `key` changes between consecutive
+    /// elements, mirroring itertools' `group_by`. Call `.defray()` on the
+    /// result to get groups whose `Group::key()` reports the key.
+    fn fray_by<K, F>(self, key: F) -> FrayBy<Self, K, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+    {
+        FrayBy::new(self, key)
+    }
+
+    /// Append a clone of `tail` after each of this iterator's frayed
+    /// groups, symmetric to [`prefix`](FraughtTools::prefix).
+    fn suffix<I>(self, tail: I) -> Suffix<I, Self>
+    where
+        Self: Frayed + Sized,
+        I: Iterator<Item = Self::Item> + Clone,
+    {
+        Suffix::new(tail, self)
+    }
 }
 
 impl<T> FraughtTools for T where T: Iterator + ?Sized {}
@@ -61,6 +109,14 @@ impl<I: Iterator> Iterator for FrayedIter<I> {
 
 impl<I: Iterator> Frayed for FrayedIter<I> {}
 
+impl<I: Iterator> Keyed for FrayedIter<I> {
+    type Key = ();
+
+    fn key(&self) -> Self::Key {}
+}
+
+impl<I: Iterator> ChunkSize for FrayedIter<I> {}
+
 // #[cfg(test)]
 // mod tests {
 //     use super::*;
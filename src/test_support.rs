@@ -0,0 +1,25 @@
+//! Test-only fixtures shared across this crate's test modules.
+use crate::defray::{ChunkSize, Keyed};
+use crate::Frayed;
+
+/// SevenIter(0) returns 1, 2, None, 4, 5, None, 7, None, None, ...
+pub(crate) struct SevenIter(pub(crate) u8);
+
+impl Iterator for SevenIter {
+    type Item = u8;
+    fn next(&mut self) -> Option<u8> {
+        self.0 += 1;
+        (self.0 % 3 != 0 && self.0 <= 7).then_some(self.0)
+    }
+}
+
+/// Mark iterator as `Frayed`
+impl Frayed for SevenIter {}
+
+impl Keyed for SevenIter {
+    type Key = ();
+
+    fn key(&self) -> Self::Key {}
+}
+
+impl ChunkSize for SevenIter {}